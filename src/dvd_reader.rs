@@ -1,7 +1,21 @@
-use std::os::raw::{c_char, c_int, c_uchar, c_uint};
+use std::ffi::CString;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_uint, c_void};
 use std::path::Path;
-use crate::{dvd_file_t, dvd_read_domain_t, dvd_read_domain_t_DVD_READ_INFO_BACKUP_FILE, dvd_read_domain_t_DVD_READ_INFO_FILE, dvd_read_domain_t_DVD_READ_MENU_VOBS, dvd_read_domain_t_DVD_READ_TITLE_VOBS, dvd_reader_t, dvd_stat_t, ifoOpen, ifoOpenVMGI, ifoOpenVTSI, ifo_handle_t, ifo_print, DVDClose, DVDDiscID, DVDFileStat, DVDISOVolumeInfo, DVDOpen, DVDOpenFile, DVDUDFCacheLevel, DVDUDFVolumeInfo, UDFFindFile, UDFGetVolumeIdentifier, UDFGetVolumeSetIdentifier};
+use std::time::Duration;
+use crate::{dvd_file_t, dvd_read_domain_t, dvd_read_domain_t_DVD_READ_INFO_BACKUP_FILE, dvd_read_domain_t_DVD_READ_INFO_FILE, dvd_read_domain_t_DVD_READ_MENU_VOBS, dvd_read_domain_t_DVD_READ_TITLE_VOBS, dvd_reader_stream_cb, dvd_reader_t, dvd_stat_t, dvd_time_t, ifoClose, ifoOpen, ifoOpenVMGI, ifoOpenVTSI, ifo_handle_t, ifo_print, title_info_t, DVDClose, DVDCloseFile, DVDDiscID, DVDFileSeek, DVDFileSeekForce, DVDFileSize, DVDFileStat, DVDISOVolumeInfo, DVDOpen, DVDOpenFile, DVDOpenStream, DVDReadBlocks, DVDReadBytes, DVDUDFCacheLevel, DVDUDFVolumeInfo, UDFFindFile, UDFGetVolumeIdentifier, UDFGetVolumeSetIdentifier, DVD_VIDEO_LB_LEN};
 
+const BLOCK_LEN: usize = DVD_VIDEO_LB_LEN as usize;
+
+extern "C" {
+    fn dup(fd: c_int) -> c_int;
+    fn dup2(oldfd: c_int, newfd: c_int) -> c_int;
+    fn close(fd: c_int) -> c_int;
+    fn fflush(stream: *mut c_void) -> c_int;
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum DvdDomain {
     InfoFile = dvd_read_domain_t_DVD_READ_INFO_FILE as isize,
     BackupFile = dvd_read_domain_t_DVD_READ_INFO_BACKUP_FILE as isize,
@@ -9,9 +23,56 @@ pub enum DvdDomain {
     TitleVobs = dvd_read_domain_t_DVD_READ_TITLE_VOBS as isize,
 }
 
+/// A single physical file in the disc's VIDEO_TS/UDF layout, as found by
+/// `DvdReader::list_files`.
+#[derive(Debug, Clone)]
+pub struct DvdFileEntry {
+    /// Canonical UDF path, e.g. `/VIDEO_TS/VTS_01_1.VOB`.
+    pub path: String,
+    /// Size of this physical file, in bytes.
+    pub size: u64,
+    /// Number of parts in the multipart file this entry belongs to (1 if
+    /// the file is not part of a multipart `TitleVobs` group).
+    pub part_count: usize,
+    /// Size of every part in the multipart group this entry belongs to, in
+    /// bytes (a single-element vec for non-multipart files).
+    pub part_sizes: Vec<u64>,
+    /// Logical block at which this file begins, as reported by
+    /// `UDFFindFile`, or 0 if it could not be located.
+    pub starting_block: u32,
+}
+
+/// The canonical UDF path for `part` (1-based) of `domain` within `title_num`.
+/// Title 0 is the video manager (`VIDEO_TS.*`) and has no `TitleVobs`.
+fn domain_path(title_num: usize, domain: DvdDomain, part: usize) -> String {
+    if title_num == 0 {
+        return match domain {
+            DvdDomain::InfoFile => "/VIDEO_TS/VIDEO_TS.IFO".to_string(),
+            DvdDomain::BackupFile => "/VIDEO_TS/VIDEO_TS.BUP".to_string(),
+            DvdDomain::MenuVobs => "/VIDEO_TS/VIDEO_TS.VOB".to_string(),
+            DvdDomain::TitleVobs => unreachable!("title 0 has no TitleVobs domain"),
+        };
+    }
+
+    match domain {
+        DvdDomain::InfoFile => format!("/VIDEO_TS/VTS_{:02}_0.IFO", title_num),
+        DvdDomain::BackupFile => format!("/VIDEO_TS/VTS_{:02}_0.BUP", title_num),
+        DvdDomain::MenuVobs => format!("/VIDEO_TS/VTS_{:02}_0.VOB", title_num),
+        DvdDomain::TitleVobs => format!("/VIDEO_TS/VTS_{:02}_{}.VOB", title_num, part),
+    }
+}
+
+/// Decode a latin-1 (ISO-8859-1) byte string, as used by `VolumeIdentifier`
+/// fields, into a `String`. Every latin-1 byte maps 1:1 onto the Unicode code
+/// point of the same value, unlike UTF-8 decoding.
+fn decode_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
 #[derive(Debug)]
 pub struct DvdReader {
     reader: dvd_reader_t,
+    stream: Option<*mut Box<dyn DvdStream>>,
 }
 
 impl DvdReader {
@@ -36,7 +97,36 @@ impl DvdReader {
     /// * path/VTS_01_1.VOB
     /// * path/vts_01_1.vob
     pub fn new(file_name: Box<Path>) -> DvdReader {
-        DvdReader { reader: unsafe { *DVDOpen(file_name.to_str().unwrap().as_ptr() as *const c_char) } }
+        DvdReader {
+            reader: unsafe { *DVDOpen(file_name.to_str().unwrap().as_ptr() as *const c_char) },
+            stream: None,
+        }
+    }
+
+    /// Create a new `DvdReader` backed by a custom `DvdStream` instead of a
+    /// local path, via `DVDOpenStream`. This lets a DVD be read from an
+    /// in-memory buffer, a network source, or a libdvdcss-decrypted stream.
+    pub fn from_stream<S: DvdStream + 'static>(stream: S) -> Result<DvdReader, String> {
+        let boxed: Box<dyn DvdStream> = Box::new(stream);
+        let stream_ptr: *mut Box<dyn DvdStream> = Box::into_raw(Box::new(boxed));
+
+        let mut callbacks = dvd_reader_stream_cb {
+            pf_seek: Some(dvd_stream_seek_cb),
+            pf_read: Some(dvd_stream_read_cb),
+            pf_readv: Some(dvd_stream_readv_cb),
+        };
+
+        let reader = unsafe { DVDOpenStream(stream_ptr as *mut c_void, &mut callbacks) };
+
+        if reader.is_null() {
+            unsafe { drop(Box::from_raw(stream_ptr)) };
+            Err("Error opening stream".to_string())
+        } else {
+            Ok(DvdReader {
+                reader: unsafe { *reader },
+                stream: Some(stream_ptr),
+            })
+        }
     }
 
     /// Close this reader
@@ -124,14 +214,23 @@ impl DvdReader {
     /// @return If successful a a file read handle is returned, otherwise 0.
     ///
     /// dvd_file = DVDOpenFile(dvd, titlenum, domain); */
-    pub fn open_file(&mut self, title_number: usize, domain: DvdDomain) -> Result<dvd_file_t, String> {
-        Ok(unsafe {
-            *DVDOpenFile(
+    ///
+    /// The returned `DvdFile` borrows this reader, so the reader cannot be
+    /// closed while any files opened from it are still alive.
+    pub fn open_file(&mut self, title_number: usize, domain: DvdDomain) -> Result<DvdFile<'_>, String> {
+        let file = unsafe {
+            DVDOpenFile(
                 &mut self.reader,
                 title_number as c_int,
                 domain as dvd_read_domain_t,
             )
-        })
+        };
+
+        if file.is_null() {
+            Err("File not found".to_string())
+        } else {
+            Ok(DvdFile::new(file))
+        }
     }
 
     /// Get the ID for this disc volume.
@@ -146,20 +245,26 @@ impl DvdReader {
     /// @param discid The buffer to put the disc ID into. The buffer must
     ///               have room for 128 bits (16 chars).
     /// @return 0 on success, -1 on error.
-    pub fn disc_id(&mut self) -> Result<String, String> {
-        let mut c_string_ptr: c_uchar = 0;
+    pub fn disc_id(&mut self) -> Result<[u8; 16], String> {
+        let mut disc_id = [0u8; 16];
         let result = unsafe { DVDDiscID(
             &mut self.reader,
-            &mut c_string_ptr,
+            disc_id.as_mut_ptr(),
         ) };
 
         if result == 0 {
-            Ok(c_string_ptr.to_string())
+            Ok(disc_id)
         } else {
             Err(format!("Error opening file: {}", result))
         }
     }
 
+    /// Like `disc_id`, but formatted as the lowercase hex string the
+    /// command-line `md5sum` program uses.
+    pub fn disc_id_hex(&mut self) -> Result<String, String> {
+        self.disc_id().map(|id| id.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
     /// Get the UDF VolumeIdentifier and VolumeSetIdentifier
     /// from the PrimaryVolumeDescriptor.
     ///
@@ -177,33 +282,25 @@ impl DvdReader {
     ///                 The VolumeIdentifier is 128 bytes as
     ///                 stored in the UDF PrimaryVolumeDescriptor.
     ///                 Note that this is not a null terminated string.
-    pub fn udf_volume_info(&mut self, volid_size: usize, volsetid_size: usize) -> Result<(String, u128), String> {
+    pub fn udf_volume_info(&mut self, volid_size: usize, volsetid_size: usize) -> Result<(String, [u8; 128]), String> {
         let mut volid = [0u8; 32];
-        let mut volsetid = [0u8; 16];
+        let mut volsetid = [0u8; 128];
 
         let result = unsafe {
             DVDUDFVolumeInfo(
                 &mut self.reader,
                 volid.as_mut_ptr() as *mut c_char,
-                volid_size as c_uint,
+                volid_size.min(volid.len()) as c_uint,
                 volsetid.as_mut_ptr(),
-                volsetid_size as c_uint,
+                volsetid_size.min(volsetid.len()) as c_uint,
             )
         };
 
         if result == 0 {
+            let nul = volid.iter().position(|&b| b == 0).unwrap_or(volid.len());
             Ok((
-                unsafe { String::from_utf8(volid.to_vec()) }.unwrap(),
-                {
-                    let mut volsetidout = 0u128;
-                    for i in 0..16 {
-                        volsetidout = volsetidout + (volsetid[i] as u128);
-                        if i < 15 {
-                            volsetidout = volsetidout << 8;
-                        }
-                    }
-                    volsetidout
-                },
+                decode_latin1(&volid[..nul]),
+                volsetid,
             ))
         } else {
             Err(format!("Error opening file: {}", result))
@@ -230,33 +327,25 @@ impl DvdReader {
     ///                 Note that this is not a null terminated string.
     /// @param volsetid_size At most volsetid_size bytes will be copied to volsetid.
     /// @return 0 on success, -1 on error.
-    pub fn iso_volume_info(&mut self, volid_size: usize, volsetid_size: usize) -> Result<(String, u128), String> {
+    pub fn iso_volume_info(&mut self, volid_size: usize, volsetid_size: usize) -> Result<(String, [u8; 128]), String> {
         let mut volid = [0u8; 32];
-        let mut volsetid = [0u8; 16];
+        let mut volsetid = [0u8; 128];
 
         let result = unsafe {
             DVDISOVolumeInfo(
                 &mut self.reader,
                 volid.as_mut_ptr() as *mut c_char,
-                volid_size as c_uint,
+                volid_size.min(volid.len()) as c_uint,
                 volsetid.as_mut_ptr(),
-                volsetid_size as c_uint,
+                volsetid_size.min(volsetid.len()) as c_uint,
             )
         };
 
         if result == 0 {
+            let nul = volid.iter().position(|&b| b == 0).unwrap_or(volid.len());
             Ok((
-                unsafe { String::from_utf8(volid.to_vec()).unwrap() },
-                {
-                    let mut volsetidout = 0u128;
-                    for i in 0..16 {
-                        volsetidout = volsetidout + (volsetid[i] as u128);
-                        if i < 15 {
-                            volsetidout = volsetidout << 8;
-                        }
-                    }
-                    volsetidout
-                },
+                decode_latin1(&volid[..nul]),
+                volsetid,
             ))
         } else {
             Err(format!("Error opening file: {}", result))
@@ -287,12 +376,13 @@ impl DvdReader {
     /// absolute pathname on the UDF filesystem, starting with '/'.  For example,
     /// '/VIDEO_TS/VTS_01_1.IFO'.  On success, filesize will be set to the size of
     /// the file in bytes.
-    pub fn udf_find_file(&mut self, path: &String) -> Result<(u32, u32), String> {
+    pub fn udf_find_file(&mut self, path: &str) -> Result<(u32, u32), String> {
+        let path = CString::new(path).map_err(|e| format!("Invalid path: {}", e))?;
         let mut filesize: u32 = 0;
         match unsafe {
             UDFFindFile(
                 &mut self.reader,
-                path.as_ptr() as *const c_char,
+                path.as_ptr(),
                 &mut filesize,
             )
         } {
@@ -301,6 +391,58 @@ impl DvdReader {
         }
     }
 
+    /// Build a manifest of every file in the disc's VIDEO_TS/UDF layout.
+    ///
+    /// Walks every title set (0..=99) across all four `DvdDomain`s, stats
+    /// each with `file_stat`, and for multipart `TitleVobs` files expands
+    /// `parts_size` into one entry per physical `VTS_nn_p.VOB` file. Each
+    /// entry's starting logical block is then resolved with `udf_find_file`.
+    pub fn list_files(&mut self) -> Vec<DvdFileEntry> {
+        const DOMAINS: [DvdDomain; 4] = [
+            DvdDomain::InfoFile,
+            DvdDomain::BackupFile,
+            DvdDomain::MenuVobs,
+            DvdDomain::TitleVobs,
+        ];
+
+        let mut entries = Vec::new();
+
+        for title_num in 0..=99usize {
+            for domain in DOMAINS {
+                if title_num == 0 && matches!(domain, DvdDomain::TitleVobs) {
+                    continue;
+                }
+
+                let stat = match self.file_stat(title_num, domain) {
+                    Ok(stat) => stat,
+                    Err(_) => continue,
+                };
+
+                let part_count = if matches!(domain, DvdDomain::TitleVobs) {
+                    (stat.nr_parts as usize).max(1)
+                } else {
+                    1
+                };
+                let part_sizes: Vec<u64> = (0..part_count).map(|i| stat.parts_size[i] as u64).collect();
+
+                for part in 1..=part_count {
+                    let path = domain_path(title_num, domain, part);
+                    let starting_block = self.udf_find_file(&path).map(|(block, _)| block).unwrap_or(0);
+
+                    entries.push(DvdFileEntry {
+                        path,
+                        size: part_sizes[part - 1],
+                        part_count,
+                        part_sizes: part_sizes.clone(),
+                        starting_block,
+                    });
+                }
+            }
+        }
+
+        entries
+    }
+
     /// Gets the Volume Identifier string, in 8bit unicode (latin-1)
     /// volid, place to put the string
     /// volid_size, size of the buffer volid points to
@@ -348,17 +490,75 @@ impl DvdReader {
         unsafe {ifo_print(&mut self.reader, title)}
     }
 
+    /// Like `ifo_print`, but captures the dump into a `String` instead of
+    /// writing it to the process's stdout.
+    ///
+    /// `ifo_print` writes through C `printf`/`fwrite`, so this works by
+    /// `dup`-ing fd 1 aside, pointing fd 1 at a disk-backed temp file for the
+    /// duration of the call, flushing C stdio, then restoring the original
+    /// fd 1 and reading the captured bytes back out of the temp file. A
+    /// `pipe()` is deliberately avoided here: its kernel buffer is bounded
+    /// (typically 64KB) and nothing drains it while the synchronous
+    /// `ifo_print` call is running, so a large dump would block it forever.
+    pub fn ifo_print_to_string(&mut self, title: i32) -> Result<String, io::Error> {
+        use std::env;
+        use std::fs::OpenOptions;
+        use std::os::unix::io::AsRawFd;
+
+        const STDOUT_FD: c_int = 1;
+
+        let saved_stdout = unsafe { dup(STDOUT_FD) };
+        if saved_stdout < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let tmp_path = env::temp_dir().join(format!("dvdread-rs-ifo-print-{}-{}.tmp", std::process::id(), title));
+        let mut tmp_file = match OpenOptions::new().create(true).truncate(true).read(true).write(true).open(&tmp_path) {
+            Ok(f) => f,
+            Err(e) => {
+                unsafe { close(saved_stdout) };
+                return Err(e);
+            }
+        };
+        // Unlink immediately; the open fd keeps the file's contents alive
+        // for as long as we need them without leaving it behind on disk.
+        let _ = std::fs::remove_file(&tmp_path);
+
+        if unsafe { dup2(tmp_file.as_raw_fd(), STDOUT_FD) } < 0 {
+            unsafe { close(saved_stdout) };
+            return Err(io::Error::last_os_error());
+        }
+
+        unsafe {
+            ifo_print(&mut self.reader, title);
+            fflush(std::ptr::null_mut());
+        }
+
+        let restore_result = unsafe { dup2(saved_stdout, STDOUT_FD) };
+        unsafe { close(saved_stdout) };
+        if restore_result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        tmp_file.seek(SeekFrom::Start(0))?;
+        let mut captured = Vec::new();
+        tmp_file.read_to_end(&mut captured)?;
+
+        Ok(String::from_utf8_lossy(&captured).into_owned())
+    }
+
     /// handle = ifoOpen(dvd, title);
     ///
     /// Opens an IFO and reads in all the data for the IFO file corresponding to the
     /// given title.  If title 0 is given, the video manager IFO file is read.
-    /// Returns a handle to a completely parsed structure.
-    pub fn ifo_open(&mut self, title: i32) -> ifo_handle_t{
-        unsafe {
-            *ifoOpen(
-                &mut self.reader,
-                title,
-            )
+    /// Returns a handle to a completely parsed structure, or an `Err` if
+    /// libdvdread returned NULL (e.g. a damaged or missing VTS).
+    pub fn ifo_open(&mut self, title: i32) -> Result<IfoHandle, String> {
+        let handle = unsafe { ifoOpen(&mut self.reader, title) };
+        if handle.is_null() {
+            Err(format!("Error opening IFO for title {}", title))
+        } else {
+            Ok(IfoHandle { handle })
         }
     }
 
@@ -367,9 +567,12 @@ impl DvdReader {
     /// Opens an IFO and reads in _only_ the vmgi_mat data.  This call can be used
     /// together with the calls below to read in each segment of the IFO file on
     /// demand.
-    pub fn ifo_open_vmgi(&mut self) -> ifo_handle_t {
-        unsafe {
-            *ifoOpenVMGI(&mut self.reader)
+    pub fn ifo_open_vmgi(&mut self) -> Result<IfoHandle, String> {
+        let handle = unsafe { ifoOpenVMGI(&mut self.reader) };
+        if handle.is_null() {
+            Err("Error opening VMGI".to_string())
+        } else {
+            Ok(IfoHandle { handle })
         }
     }
 
@@ -378,14 +581,406 @@ impl DvdReader {
     /// Opens an IFO and reads in _only_ the vtsi_mat data.  This call can be used
     /// together with the calls below to read in each segment of the IFO file on
     /// demand.
-    pub fn ifo_open_vtsi(&mut self, title: i32) -> ifo_handle_t {
-        unsafe {
-            *ifoOpenVTSI(
-                &mut self.reader,
-                title,
+    pub fn ifo_open_vtsi(&mut self, title: i32) -> Result<IfoHandle, String> {
+        let handle = unsafe { ifoOpenVTSI(&mut self.reader, title) };
+        if handle.is_null() {
+            Err(format!("Error opening VTSI for title {}", title))
+        } else {
+            Ok(IfoHandle { handle })
+        }
+    }
+
+    /// Build a ripper-style table of contents for every title on the disc,
+    /// by parsing the VMGI `tt_srpt` table and, for each title, the owning
+    /// VTSI's `vts_ptt_srpt`/`vts_pgcit` tables.
+    pub fn titles(&mut self) -> Result<Vec<DvdTitle>, String> {
+        let vmgi = self.ifo_open(0)?;
+        let tt_srpt = unsafe { vmgi.tt_srpt.as_ref() }.ok_or("Missing tt_srpt table in VMGI")?;
+        let title_infos = unsafe { std::slice::from_raw_parts(tt_srpt.title, tt_srpt.nr_of_srpts as usize) };
+
+        let mut titles = Vec::with_capacity(title_infos.len());
+        for (i, info) in title_infos.iter().enumerate() {
+            titles.push(DvdTitle {
+                title_number: (i + 1) as u8,
+                vts_number: info.title_set_nr,
+                angle_count: info.nr_of_angles,
+                chapters: self.title_chapters(info)?,
+            });
+        }
+
+        Ok(titles)
+    }
+
+    /// Resolve the chapters (and their cells) for a single `title_info_t`
+    /// entry, by walking the owning VTS's `vts_ptt_srpt` -> `vts_pgcit` ->
+    /// `pgc_t` chain.
+    fn title_chapters(&mut self, info: &title_info_t) -> Result<Vec<DvdChapter>, String> {
+        let vtsi = self.ifo_open(info.title_set_nr as i32)?;
+        let ptt_srpt = unsafe { vtsi.vts_ptt_srpt.as_ref() }.ok_or("Missing vts_ptt_srpt table in VTSI")?;
+        let pgcit = unsafe { vtsi.vts_pgcit.as_ref() }.ok_or("Missing vts_pgcit table in VTSI")?;
+
+        let ttu = unsafe { &*ptt_srpt.title.add((info.vts_ttn - 1) as usize) };
+        let ptts = unsafe { std::slice::from_raw_parts(ttu.ptt, ttu.nr_of_ptts as usize) };
+        let pgci_srp = unsafe { std::slice::from_raw_parts(pgcit.pgci_srp, pgcit.nr_of_pgci as usize) };
+
+        let mut chapters = Vec::with_capacity(ptts.len());
+        for (i, ptt) in ptts.iter().enumerate() {
+            let pgc = unsafe { &*pgci_srp[(ptt.pgcn - 1) as usize].pgc };
+            let program_map = unsafe { std::slice::from_raw_parts(pgc.program_map, pgc.nr_of_programs as usize) };
+            let cell_playback = unsafe { std::slice::from_raw_parts(pgc.cell_playback, pgc.nr_of_cells as usize) };
+
+            let start_cell = program_map[(ptt.pgn - 1) as usize] as usize;
+            let end_cell = if (ptt.pgn as usize) < program_map.len() {
+                program_map[ptt.pgn as usize] as usize - 1
+            } else {
+                pgc.nr_of_cells as usize
+            };
+
+            let cells = (start_cell..=end_cell)
+                .map(|cell_number| DvdCell {
+                    cell_number: cell_number as u8,
+                    duration: decode_dvd_time(&cell_playback[cell_number - 1].playback_time),
+                })
+                .collect();
+
+            chapters.push(DvdChapter {
+                chapter_number: (i + 1) as u8,
+                cells,
+            });
+        }
+
+        Ok(chapters)
+    }
+
+}
+
+impl Drop for DvdReader {
+    fn drop(&mut self) {
+        if let Some(stream_ptr) = self.stream.take() {
+            unsafe { drop(Box::from_raw(stream_ptr)) };
+        }
+    }
+}
+
+/// An IFO handle opened via `ifo_open`/`ifo_open_vmgi`/`ifo_open_vtsi`.
+///
+/// Holds the raw pointer `ifoOpen`/`ifoOpenVMGI`/`ifoOpenVTSI` returned so it
+/// can be released with `ifoClose` on that same pointer, and derefs to the
+/// parsed `ifo_handle_t` for field access.
+pub struct IfoHandle {
+    handle: *mut ifo_handle_t,
+}
+
+impl std::ops::Deref for IfoHandle {
+    type Target = ifo_handle_t;
+
+    fn deref(&self) -> &ifo_handle_t {
+        unsafe { &*self.handle }
+    }
+}
+
+impl Drop for IfoHandle {
+    fn drop(&mut self) {
+        unsafe { ifoClose(self.handle) }
+    }
+}
+
+/// A single cell within a chapter, with its playback duration.
+#[derive(Debug, Clone, Copy)]
+pub struct DvdCell {
+    pub cell_number: u8,
+    pub duration: Duration,
+}
+
+/// A chapter (PTT) within a title, made up of one or more cells.
+#[derive(Debug, Clone)]
+pub struct DvdChapter {
+    pub chapter_number: u8,
+    pub cells: Vec<DvdCell>,
+}
+
+impl DvdChapter {
+    /// Total playback time of the chapter, i.e. the sum of its cells' durations.
+    pub fn duration(&self) -> Duration {
+        self.cells.iter().map(|cell| cell.duration).sum()
+    }
+}
+
+/// A title on the disc, with its chapters derived from the VMGI/VTSI tables.
+#[derive(Debug, Clone)]
+pub struct DvdTitle {
+    pub title_number: u8,
+    pub vts_number: u8,
+    pub angle_count: u8,
+    pub chapters: Vec<DvdChapter>,
+}
+
+impl DvdTitle {
+    /// Number of chapters in the title.
+    pub fn chapter_count(&self) -> usize {
+        self.chapters.len()
+    }
+
+    /// Total playback time of the title, i.e. the sum of its chapters' durations.
+    pub fn duration(&self) -> Duration {
+        self.chapters.iter().map(|chapter| chapter.duration()).sum()
+    }
+}
+
+/// Decode a BCD-encoded `dvd_time_t` into a `Duration`.
+///
+/// Hour/minute/second are packed as two BCD digits per byte; the frame byte
+/// packs a 2-bit frame-rate code (0b11 = 30fps, 0b10 = 25fps) in the top bits
+/// and the BCD frame count in the bottom 6 bits.
+fn decode_dvd_time(time: &dvd_time_t) -> Duration {
+    fn bcd(byte: u8) -> u64 {
+        ((byte >> 4) * 10 + (byte & 0x0f)) as u64
+    }
+
+    let hours = bcd(time.hour);
+    let minutes = bcd(time.minute);
+    let seconds = bcd(time.second);
+    let frame_rate = (time.frame_u & 0xc0) >> 6;
+    let frames = bcd(time.frame_u & 0x3f);
+    let fps = match frame_rate {
+        0b11 => 30.0,
+        0b10 => 25.0,
+        _ => 25.0,
+    };
+
+    let whole = Duration::from_secs(hours * 3600 + minutes * 60 + seconds);
+    let fractional = Duration::from_secs_f64(frames as f64 / fps);
+    whole + fractional
+}
+
+/// A custom source of DVD data, fed to libdvdread via `DVDOpenStream` instead
+/// of a local path. Lets a disc be read from an in-memory buffer, a network
+/// source, or a libdvdcss-decrypted stream.
+pub trait DvdStream {
+    /// Seek to an absolute byte position, as `pf_seek` does.
+    fn seek(&mut self, pos: u64) -> io::Result<()>;
+
+    /// Read into `buf`, as `pf_read` does, returning the number of bytes read.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Read into several buffers in one call, as `pf_readv` does. The default
+    /// implementation falls back to repeated calls to `read`, stopping at the
+    /// first short read.
+    fn readv(&mut self, bufs: &mut [&mut [u8]]) -> io::Result<usize> {
+        let mut total = 0;
+        for buf in bufs {
+            let n = self.read(buf)?;
+            total += n;
+            if n < buf.len() {
+                break;
+            }
+        }
+        Ok(total)
+    }
+}
+
+/// Layout of a POSIX `struct iovec`, as passed to `pf_readv`.
+#[repr(C)]
+struct RawIoVec {
+    iov_base: *mut c_void,
+    iov_len: usize,
+}
+
+unsafe extern "C" fn dvd_stream_seek_cb(stream: *mut c_void, pos: u64) -> c_int {
+    let stream = &mut *(stream as *mut Box<dyn DvdStream>);
+    match stream.seek(pos) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+unsafe extern "C" fn dvd_stream_read_cb(stream: *mut c_void, buf: *mut c_void, count: c_int) -> c_int {
+    let stream = &mut *(stream as *mut Box<dyn DvdStream>);
+    let buf = std::slice::from_raw_parts_mut(buf as *mut u8, count as usize);
+    match stream.read(buf) {
+        Ok(n) => n as c_int,
+        Err(_) => -1,
+    }
+}
+
+unsafe extern "C" fn dvd_stream_readv_cb(stream: *mut c_void, iovec: *mut c_void, blocks: c_int) -> c_int {
+    let stream = &mut *(stream as *mut Box<dyn DvdStream>);
+    let iovec = std::slice::from_raw_parts(iovec as *const RawIoVec, blocks as usize);
+    let mut bufs: Vec<&mut [u8]> = iovec
+        .iter()
+        .map(|v| std::slice::from_raw_parts_mut(v.iov_base as *mut u8, v.iov_len))
+        .collect();
+
+    match stream.readv(&mut bufs) {
+        Ok(n) => n as c_int,
+        Err(_) => -1,
+    }
+}
+
+/// A file opened on a DVD (VIDEO_TS.VOB, a VTS_nn_p.VOB, an IFO, ...).
+///
+/// Borrows the `DvdReader` that opened it for as long as the file is alive,
+/// since libdvdread requires every open file to be closed before its reader
+/// is. Implements `Read` and `Seek` by buffering whole 2048-byte logical
+/// blocks internally; use `read_blocks` instead if you want raw block access
+/// without going through the byte-level cursor.
+pub struct DvdFile<'a> {
+    file: *mut dvd_file_t,
+    _reader: PhantomData<&'a mut DvdReader>,
+    offset: u64,
+    block: Option<(u32, [u8; BLOCK_LEN])>,
+}
+
+impl<'a> DvdFile<'a> {
+    fn new(file: *mut dvd_file_t) -> Self {
+        DvdFile {
+            file,
+            _reader: PhantomData,
+            offset: 0,
+            block: None,
+        }
+    }
+
+    /// Size of the file, in 2048-byte logical blocks, as reported by `DVDFileSize`.
+    pub fn block_count(&mut self) -> Result<u32, String> {
+        let result = unsafe { DVDFileSize(self.file) };
+        if result < 0 {
+            Err(format!("Error getting file size: {}", result))
+        } else {
+            Ok(result as u32)
+        }
+    }
+
+    /// Size of the file, in bytes.
+    pub fn size(&mut self) -> Result<u64, String> {
+        self.block_count().map(|blocks| blocks as u64 * DVD_VIDEO_LB_LEN as u64)
+    }
+
+    /// Read `count` logical blocks starting at block `offset`, bypassing the
+    /// byte-level buffering used by `Read`.
+    ///
+    /// `offset` and `count` are in units of `DVD_VIDEO_LB_LEN` (2048 byte) blocks.
+    pub fn read_blocks(&mut self, offset: u32, count: usize) -> Result<Vec<u8>, String> {
+        let mut buf = vec![0u8; count * BLOCK_LEN];
+        let result = unsafe {
+            DVDReadBlocks(
+                self.file,
+                offset as c_int,
+                count,
+                buf.as_mut_ptr(),
             )
+        };
+
+        if result < 0 {
+            return Err(format!("Error reading blocks: {}", result));
+        }
+
+        buf.truncate(result as usize * BLOCK_LEN);
+        Ok(buf)
+    }
+
+    /// Read `count` bytes directly via `DVDReadBytes`, without regard to
+    /// block alignment or the byte-level cursor used by `Read`.
+    pub fn read_bytes(&mut self, count: usize) -> Result<Vec<u8>, String> {
+        let mut buf = vec![0u8; count];
+        let result = unsafe {
+            DVDReadBytes(self.file, buf.as_mut_ptr() as *mut c_void, count)
+        };
+
+        if result < 0 {
+            return Err(format!("Error reading bytes: {}", result));
+        }
+
+        buf.truncate(result as usize);
+        Ok(buf)
+    }
+
+    /// Seek to a block-aligned `offset`, as `DVDFileSeek` does. Returns the
+    /// new offset reported by libdvdread and also moves the byte-level cursor
+    /// used by `Read`/`Seek` to match.
+    pub fn dvd_seek(&mut self, offset: i32) -> Result<i32, String> {
+        let result = unsafe { DVDFileSeek(self.file, offset) };
+        if result < 0 {
+            Err(format!("Error seeking file: {}", result))
+        } else {
+            self.offset = result as u64;
+            self.block = None;
+            Ok(result)
         }
     }
 
+    /// Seek as `dvd_seek` does, but via `DVDFileSeekForce`, which allows
+    /// seeking past the size libdvdread has cached for a still-growing file
+    /// (e.g. one being recorded live) by passing `force_size`.
+    pub fn dvd_seek_force(&mut self, offset: i32, force_size: i32) -> Result<i32, String> {
+        let result = unsafe { DVDFileSeekForce(self.file, offset, force_size) };
+        if result < 0 {
+            Err(format!("Error seeking file: {}", result))
+        } else {
+            self.offset = result as u64;
+            self.block = None;
+            Ok(result)
+        }
+    }
+
+    /// Fetch the logical block containing `self.offset`, from cache if possible.
+    fn current_block(&mut self) -> io::Result<&[u8; BLOCK_LEN]> {
+        let block_num = (self.offset / BLOCK_LEN as u64) as u32;
+
+        if self.block.as_ref().map(|(n, _)| *n) != Some(block_num) {
+            let mut buf = [0u8; BLOCK_LEN];
+            let result = unsafe { DVDReadBlocks(self.file, block_num as c_int, 1, buf.as_mut_ptr()) };
+            if result != 1 {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Error reading block {}: {}", block_num, result),
+                ));
+            }
+            self.block = Some((block_num, buf));
+        }
+
+        Ok(&self.block.as_ref().unwrap().1)
+    }
+}
+
+impl<'a> Read for DvdFile<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let total_bytes = self.size().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        if self.offset >= total_bytes {
+            return Ok(0);
+        }
+
+        let in_block = (self.offset % BLOCK_LEN as u64) as usize;
+        let remaining_in_file = (total_bytes - self.offset) as usize;
+        let block = self.current_block()?;
+        let n = buf.len().min(BLOCK_LEN - in_block).min(remaining_in_file);
 
+        buf[..n].copy_from_slice(&block[in_block..in_block + n]);
+        self.offset += n as u64;
+        Ok(n)
+    }
+}
+
+impl<'a> Seek for DvdFile<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let total_bytes = self.size().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let new_offset = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => total_bytes as i64 + offset,
+            SeekFrom::Current(offset) => self.offset as i64 + offset,
+        };
+
+        if new_offset < 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid seek to a negative position"));
+        }
+
+        self.offset = new_offset as u64;
+        Ok(self.offset)
+    }
+}
+
+impl<'a> Drop for DvdFile<'a> {
+    fn drop(&mut self) {
+        unsafe { DVDCloseFile(self.file) }
+    }
 }
\ No newline at end of file